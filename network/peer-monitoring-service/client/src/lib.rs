@@ -0,0 +1,52 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod metrics;
+mod network;
+pub mod peer_states;
+pub mod persistence;
+
+use aptos_network::{application::interface::NetworkClientInterface, protocols::network::RpcError};
+use aptos_peer_monitoring_service_types::PeerMonitoringServiceMessage;
+use thiserror::Error;
+
+/// A simple wrapper around the network client, used to send peer monitoring
+/// service requests to a peer and deserialize the corresponding responses.
+#[derive(Clone, Debug)]
+pub struct PeerMonitoringServiceClient<NetworkClient> {
+    network_client: NetworkClient,
+}
+
+impl<NetworkClient: NetworkClientInterface<PeerMonitoringServiceMessage>>
+    PeerMonitoringServiceClient<NetworkClient>
+{
+    pub fn new(network_client: NetworkClient) -> Self {
+        Self { network_client }
+    }
+
+    pub fn get_network_client(&self) -> &NetworkClient {
+        &self.network_client
+    }
+}
+
+/// An error type for peer monitoring client operations
+#[derive(Clone, Debug, Error)]
+pub enum Error {
+    #[error("Network error: {0}")]
+    NetworkError(String),
+
+    #[error("Unexpected error: {0}")]
+    UnexpectedError(String),
+}
+
+impl From<aptos_peer_monitoring_service_types::Error> for Error {
+    fn from(error: aptos_peer_monitoring_service_types::Error) -> Self {
+        Error::UnexpectedError(error.to_string())
+    }
+}
+
+impl From<RpcError> for Error {
+    fn from(error: RpcError) -> Self {
+        Error::NetworkError(error.to_string())
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_config::network_id::PeerNetworkId;
+use aptos_metrics_core::{register_histogram_vec, register_gauge_vec, GaugeVec, HistogramVec};
+use once_cell::sync::Lazy;
+
+/// Observes a value for the given metric, labelled by request type, peer
+/// network id and the peer's current connection-health status
+pub fn observe_value(
+    histogram: &Lazy<HistogramVec>,
+    label: &str,
+    peer_network_id: &PeerNetworkId,
+    connection_health_status: &str,
+    value: f64,
+) {
+    histogram
+        .with_label_values(&[
+            label,
+            &peer_network_id.network_id().to_string(),
+            connection_health_status,
+        ])
+        .observe(value);
+}
+
+/// Counter for the latencies of peer monitoring service requests
+pub static REQUEST_LATENCIES: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "aptos_peer_monitoring_service_client_request_latencies",
+        "Counters related to peer monitoring service request latencies",
+        &["request_label", "network_id", "connection_health"]
+    )
+    .unwrap()
+});
+
+/// Gauge for the exponentially weighted moving average of a peer's ping latency
+pub static LATENCY_PING_EWMA_SECS: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "aptos_peer_monitoring_service_client_latency_ping_ewma_secs",
+        "The exponentially weighted moving average of a peer's ping latency (in seconds)",
+        &["network_id", "peer_id"]
+    )
+    .unwrap()
+});
+
+/// Gauge for the sliding-window latency percentiles (p50/p90/p99) of a peer's ping latency
+pub static LATENCY_PING_PERCENTILE_SECS: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "aptos_peer_monitoring_service_client_latency_ping_percentile_secs",
+        "The sliding-window latency percentiles of a peer's ping latency (in seconds)",
+        &["network_id", "peer_id", "percentile"]
+    )
+    .unwrap()
+});
+
+/// Sets a gauge value for the given metric, labelled by peer network id and peer id
+pub fn set_gauge(gauge: &Lazy<GaugeVec>, peer_network_id: &PeerNetworkId, value: f64) {
+    gauge
+        .with_label_values(&[
+            &peer_network_id.network_id().to_string(),
+            &peer_network_id.peer_id().to_string(),
+        ])
+        .set(value);
+}
+
+/// Gauge for the rolling goodput (bandwidth) estimate observed for a peer
+#[cfg(feature = "network-perf-test")] // Disabled by default
+pub static PEER_GOODPUT_BYTES_PER_SEC: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "aptos_peer_monitoring_service_client_goodput_bytes_per_sec",
+        "The rolling goodput (bandwidth) estimate observed for a peer, in bytes/sec",
+        &["network_id", "peer_id", "direction"]
+    )
+    .unwrap()
+});
+
+/// Sets a gauge value for the given metric, labelled by peer network id, peer id and percentile
+pub fn set_percentile_gauge(
+    gauge: &Lazy<GaugeVec>,
+    peer_network_id: &PeerNetworkId,
+    percentile: &str,
+    value: f64,
+) {
+    gauge
+        .with_label_values(&[
+            &peer_network_id.network_id().to_string(),
+            &peer_network_id.peer_id().to_string(),
+            percentile,
+        ])
+        .set(value);
+}
+
+/// Sets a gauge value for the given metric, labelled by peer network id, peer id and direction
+/// (i.e., "upload" or "download")
+#[cfg(feature = "network-perf-test")] // Disabled by default
+pub fn set_goodput_gauge(
+    gauge: &Lazy<GaugeVec>,
+    peer_network_id: &PeerNetworkId,
+    direction: &str,
+    value: f64,
+) {
+    gauge
+        .with_label_values(&[
+            &peer_network_id.network_id().to_string(),
+            &peer_network_id.peer_id().to_string(),
+            direction,
+        ])
+        .set(value);
+}
@@ -0,0 +1,43 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{Error, PeerMonitoringServiceClient};
+use aptos_config::network_id::PeerNetworkId;
+use aptos_network::application::interface::NetworkClientInterface;
+use aptos_peer_monitoring_service_types::{
+    request::PeerMonitoringServiceRequest, response::PeerMonitoringServiceResponse,
+    PeerMonitoringServiceMessage,
+};
+
+/// Sends a peer monitoring service request to the specified peer and waits
+/// for (and deserializes) the corresponding response.
+pub async fn send_request_to_peer<NetworkClient: NetworkClientInterface<PeerMonitoringServiceMessage>>(
+    peer_monitoring_client: PeerMonitoringServiceClient<NetworkClient>,
+    peer_network_id: &PeerNetworkId,
+    request_id: u64,
+    request: PeerMonitoringServiceRequest,
+    request_timeout_ms: u64,
+) -> Result<PeerMonitoringServiceResponse, Error> {
+    let response = peer_monitoring_client
+        .get_network_client()
+        .send_to_peer_rpc(
+            PeerMonitoringServiceMessage::Request(request),
+            request_timeout_ms,
+            *peer_network_id,
+        )
+        .await?;
+
+    match response {
+        PeerMonitoringServiceMessage::Response(Ok(response)) => Ok(response),
+        PeerMonitoringServiceMessage::Response(Err(error)) => {
+            Err(Error::UnexpectedError(format!(
+                "Peer {:?} returned an error for request id {:?}: {:?}",
+                peer_network_id, request_id, error
+            )))
+        },
+        PeerMonitoringServiceMessage::Request(_) => Err(Error::UnexpectedError(format!(
+            "Received an unexpected request instead of a response from peer {:?} (request id {:?})",
+            peer_network_id, request_id
+        ))),
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    peer_states::{
+        connection_health::ConnectionHealthState, known_peers::KnownPeersState,
+        latency_info::LatencyInfoState, network_info::NetworkInfoState,
+        node_info::NodeInfoState, request_tracker::RequestTracker,
+    },
+    Error,
+};
+#[cfg(feature = "network-perf-test")] // Disabled by default
+use crate::peer_states::performance_monitoring::PerformanceMonitoringState;
+use aptos_config::{config::NodeConfig, network_id::PeerNetworkId};
+use aptos_infallible::RwLock;
+use aptos_network::application::metadata::PeerMetadata;
+use aptos_peer_monitoring_service_types::{
+    request::PeerMonitoringServiceRequest, response::PeerMonitoringServiceResponse,
+};
+use aptos_time_service::TimeService;
+use std::sync::Arc;
+
+/// A key used to identify a single piece of state tracked for a peer
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum PeerStateKey {
+    ConnectionHealth,
+    KnownPeers,
+    LatencyInfo,
+    NetworkInfo,
+    NodeInfo,
+    #[cfg(feature = "network-perf-test")] // Disabled by default
+    PerformanceMonitoring,
+}
+
+impl PeerStateKey {
+    /// Returns all peer state keys that are refreshed by sending a monitoring
+    /// request to the peer. Note: `ConnectionHealth` is deliberately excluded.
+    /// It is a derived aggregate, fed by the outcomes of the other request
+    /// types, and never has a wire request of its own (see `connection_health`).
+    pub fn get_all_keys() -> Vec<Self> {
+        vec![
+            PeerStateKey::KnownPeers,
+            PeerStateKey::LatencyInfo,
+            PeerStateKey::NetworkInfo,
+            PeerStateKey::NodeInfo,
+            #[cfg(feature = "network-perf-test")] // Disabled by default
+            PeerStateKey::PerformanceMonitoring,
+        ]
+    }
+}
+
+/// A common interface implemented by every peer state value
+pub trait StateValueInterface {
+    /// Creates a new monitoring service request for this state value
+    fn create_monitoring_service_request(&self) -> PeerMonitoringServiceRequest;
+
+    /// Returns the request timeout (in milliseconds) for this state value
+    fn get_request_timeout_ms(&self) -> u64;
+
+    /// Returns the request tracker for this state value
+    fn get_request_tracker(&self) -> Arc<RwLock<RequestTracker>>;
+
+    /// Handles a successful monitoring service response
+    fn handle_monitoring_service_response(
+        &mut self,
+        peer_network_id: &PeerNetworkId,
+        peer_metadata: PeerMetadata,
+        monitoring_service_request: PeerMonitoringServiceRequest,
+        monitoring_service_response: PeerMonitoringServiceResponse,
+        response_time_secs: f64,
+    );
+
+    /// Handles an error encountered while sending a monitoring service request
+    fn handle_monitoring_service_response_error(
+        &mut self,
+        peer_network_id: &PeerNetworkId,
+        error: Error,
+    );
+}
+
+/// A state value tracked for a peer, keyed by `PeerStateKey`
+#[derive(Clone, Debug)]
+pub enum PeerStateValue {
+    ConnectionHealthState(ConnectionHealthState),
+    KnownPeersState(KnownPeersState),
+    LatencyInfoState(LatencyInfoState),
+    NetworkInfoState(NetworkInfoState),
+    NodeInfoState(NodeInfoState),
+    #[cfg(feature = "network-perf-test")] // Disabled by default
+    PerformanceMonitoringState(PerformanceMonitoringState),
+}
+
+impl PeerStateValue {
+    pub fn new(
+        node_config: NodeConfig,
+        time_service: TimeService,
+        peer_state_key: &PeerStateKey,
+    ) -> Self {
+        match peer_state_key {
+            PeerStateKey::ConnectionHealth => PeerStateValue::ConnectionHealthState(
+                ConnectionHealthState::new(node_config, time_service),
+            ),
+            PeerStateKey::KnownPeers => {
+                PeerStateValue::KnownPeersState(KnownPeersState::new(node_config, time_service))
+            },
+            PeerStateKey::LatencyInfo => {
+                PeerStateValue::LatencyInfoState(LatencyInfoState::new(node_config, time_service))
+            },
+            PeerStateKey::NetworkInfo => {
+                PeerStateValue::NetworkInfoState(NetworkInfoState::new(node_config, time_service))
+            },
+            PeerStateKey::NodeInfo => {
+                PeerStateValue::NodeInfoState(NodeInfoState::new(node_config, time_service))
+            },
+            #[cfg(feature = "network-perf-test")] // Disabled by default
+            PeerStateKey::PerformanceMonitoring => PeerStateValue::PerformanceMonitoringState(
+                PerformanceMonitoringState::new(node_config, time_service),
+            ),
+        }
+    }
+}
+
+impl StateValueInterface for PeerStateValue {
+    fn create_monitoring_service_request(&self) -> PeerMonitoringServiceRequest {
+        match self {
+            PeerStateValue::ConnectionHealthState(state) => state.create_monitoring_service_request(),
+            PeerStateValue::KnownPeersState(state) => state.create_monitoring_service_request(),
+            PeerStateValue::LatencyInfoState(state) => state.create_monitoring_service_request(),
+            PeerStateValue::NetworkInfoState(state) => state.create_monitoring_service_request(),
+            PeerStateValue::NodeInfoState(state) => state.create_monitoring_service_request(),
+            #[cfg(feature = "network-perf-test")] // Disabled by default
+            PeerStateValue::PerformanceMonitoringState(state) => {
+                state.create_monitoring_service_request()
+            },
+        }
+    }
+
+    fn get_request_timeout_ms(&self) -> u64 {
+        match self {
+            PeerStateValue::ConnectionHealthState(state) => state.get_request_timeout_ms(),
+            PeerStateValue::KnownPeersState(state) => state.get_request_timeout_ms(),
+            PeerStateValue::LatencyInfoState(state) => state.get_request_timeout_ms(),
+            PeerStateValue::NetworkInfoState(state) => state.get_request_timeout_ms(),
+            PeerStateValue::NodeInfoState(state) => state.get_request_timeout_ms(),
+            #[cfg(feature = "network-perf-test")] // Disabled by default
+            PeerStateValue::PerformanceMonitoringState(state) => state.get_request_timeout_ms(),
+        }
+    }
+
+    fn get_request_tracker(&self) -> Arc<RwLock<RequestTracker>> {
+        match self {
+            PeerStateValue::ConnectionHealthState(state) => state.get_request_tracker(),
+            PeerStateValue::KnownPeersState(state) => state.get_request_tracker(),
+            PeerStateValue::LatencyInfoState(state) => state.get_request_tracker(),
+            PeerStateValue::NetworkInfoState(state) => state.get_request_tracker(),
+            PeerStateValue::NodeInfoState(state) => state.get_request_tracker(),
+            #[cfg(feature = "network-perf-test")] // Disabled by default
+            PeerStateValue::PerformanceMonitoringState(state) => state.get_request_tracker(),
+        }
+    }
+
+    fn handle_monitoring_service_response(
+        &mut self,
+        peer_network_id: &PeerNetworkId,
+        peer_metadata: PeerMetadata,
+        monitoring_service_request: PeerMonitoringServiceRequest,
+        monitoring_service_response: PeerMonitoringServiceResponse,
+        response_time_secs: f64,
+    ) {
+        match self {
+            PeerStateValue::ConnectionHealthState(state) => state.handle_monitoring_service_response(
+                peer_network_id,
+                peer_metadata,
+                monitoring_service_request,
+                monitoring_service_response,
+                response_time_secs,
+            ),
+            PeerStateValue::KnownPeersState(state) => state.handle_monitoring_service_response(
+                peer_network_id,
+                peer_metadata,
+                monitoring_service_request,
+                monitoring_service_response,
+                response_time_secs,
+            ),
+            PeerStateValue::LatencyInfoState(state) => state.handle_monitoring_service_response(
+                peer_network_id,
+                peer_metadata,
+                monitoring_service_request,
+                monitoring_service_response,
+                response_time_secs,
+            ),
+            PeerStateValue::NetworkInfoState(state) => state.handle_monitoring_service_response(
+                peer_network_id,
+                peer_metadata,
+                monitoring_service_request,
+                monitoring_service_response,
+                response_time_secs,
+            ),
+            PeerStateValue::NodeInfoState(state) => state.handle_monitoring_service_response(
+                peer_network_id,
+                peer_metadata,
+                monitoring_service_request,
+                monitoring_service_response,
+                response_time_secs,
+            ),
+            #[cfg(feature = "network-perf-test")] // Disabled by default
+            PeerStateValue::PerformanceMonitoringState(state) => state
+                .handle_monitoring_service_response(
+                    peer_network_id,
+                    peer_metadata,
+                    monitoring_service_request,
+                    monitoring_service_response,
+                    response_time_secs,
+                ),
+        }
+    }
+
+    fn handle_monitoring_service_response_error(
+        &mut self,
+        peer_network_id: &PeerNetworkId,
+        error: Error,
+    ) {
+        match self {
+            PeerStateValue::ConnectionHealthState(state) => {
+                state.handle_monitoring_service_response_error(peer_network_id, error)
+            },
+            PeerStateValue::KnownPeersState(state) => {
+                state.handle_monitoring_service_response_error(peer_network_id, error)
+            },
+            PeerStateValue::LatencyInfoState(state) => {
+                state.handle_monitoring_service_response_error(peer_network_id, error)
+            },
+            PeerStateValue::NetworkInfoState(state) => {
+                state.handle_monitoring_service_response_error(peer_network_id, error)
+            },
+            PeerStateValue::NodeInfoState(state) => {
+                state.handle_monitoring_service_response_error(peer_network_id, error)
+            },
+            #[cfg(feature = "network-perf-test")] // Disabled by default
+            PeerStateValue::PerformanceMonitoringState(state) => {
+                state.handle_monitoring_service_response_error(peer_network_id, error)
+            },
+        }
+    }
+}
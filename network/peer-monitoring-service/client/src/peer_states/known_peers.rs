@@ -0,0 +1,199 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    peer_states::{key_value::StateValueInterface, request_tracker::RequestTracker},
+    Error,
+};
+use aptos_config::{config::NodeConfig, network_id::PeerNetworkId};
+use aptos_infallible::RwLock;
+use aptos_network::application::metadata::PeerMetadata;
+use aptos_peer_monitoring_service_types::{
+    request::PeerMonitoringServiceRequest,
+    response::{KnownPeerInfo, PeerMonitoringServiceResponse},
+};
+use aptos_time_service::{TimeService, TimeServiceTrait};
+use aptos_types::PeerId;
+use std::{collections::HashMap, sync::Arc, time::UNIX_EPOCH};
+
+/// The maximum number of candidate peers retained in the pool. Once the pool
+/// is full, the oldest (least fresh) entries are evicted to make room.
+const MAX_CANDIDATE_POOL_SIZE: usize = 200;
+
+/// The maximum amount of time (in seconds) a candidate peer is retained
+/// without being refreshed, before it is considered stale and dropped
+const CANDIDATE_STALENESS_SECS: u64 = 24 * 60 * 60; // 1 day
+
+/// The state tracked for outstanding and completed known peers requests
+#[derive(Clone, Debug)]
+pub struct KnownPeersState {
+    candidate_pool: HashMap<PeerId, KnownPeerInfo>, // The pool of candidate peers discovered via gossip
+    request_timeout_ms: u64, // The timeout for known peers requests
+    request_tracker: Arc<RwLock<RequestTracker>>, // The tracker for known peers requests
+    time_service: TimeService, // The time service used to gauge candidate staleness
+}
+
+impl KnownPeersState {
+    pub fn new(node_config: NodeConfig, time_service: TimeService) -> Self {
+        let monitoring_service_config = node_config.peer_monitoring_service;
+        let request_tracker = RequestTracker::new(
+            time_service.clone(),
+            monitoring_service_config.known_peers_request_interval_ms,
+        );
+
+        Self {
+            candidate_pool: HashMap::new(),
+            request_timeout_ms: monitoring_service_config.known_peers_request_timeout_ms,
+            request_tracker: Arc::new(RwLock::new(request_tracker)),
+            time_service,
+        }
+    }
+
+    /// Merges a newly received set of known peers into the candidate pool,
+    /// then prunes stale and excess entries
+    fn merge_known_peers(&mut self, known_peers: Vec<KnownPeerInfo>) {
+        for known_peer in known_peers {
+            self.candidate_pool.insert(known_peer.peer_id, known_peer);
+        }
+
+        self.remove_stale_candidates();
+        self.enforce_candidate_pool_size();
+    }
+
+    /// Removes candidates that haven't been refreshed within the staleness window
+    fn remove_stale_candidates(&mut self) {
+        let now_usecs = self
+            .time_service
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .as_micros() as u64;
+        let staleness_usecs = CANDIDATE_STALENESS_SECS * 1_000_000;
+
+        self.candidate_pool
+            .retain(|_, known_peer_info| {
+                now_usecs.saturating_sub(known_peer_info.freshness_timestamp_usecs) < staleness_usecs
+            });
+    }
+
+    /// Evicts the oldest candidates until the pool is within its size bound
+    fn enforce_candidate_pool_size(&mut self) {
+        while self.candidate_pool.len() > MAX_CANDIDATE_POOL_SIZE {
+            if let Some(oldest_peer_id) = self
+                .candidate_pool
+                .iter()
+                .min_by_key(|(_, known_peer_info)| known_peer_info.freshness_timestamp_usecs)
+                .map(|(peer_id, _)| *peer_id)
+            {
+                self.candidate_pool.remove(&oldest_peer_id);
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Returns the current candidate pool of known peers
+    pub fn get_candidate_pool(&self) -> Vec<KnownPeerInfo> {
+        self.candidate_pool.values().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_peer_monitoring_service_types::response::PeerCapabilities;
+
+    fn known_peer(peer_id: PeerId, freshness_timestamp_usecs: u64) -> KnownPeerInfo {
+        KnownPeerInfo {
+            peer_id,
+            capabilities: PeerCapabilities::empty(),
+            freshness_timestamp_usecs,
+        }
+    }
+
+    #[test]
+    fn merge_known_peers_overwrites_existing_entries() {
+        let mut state = KnownPeersState::new(NodeConfig::default(), TimeService::mock());
+        let peer_id = PeerId::random();
+
+        state.merge_known_peers(vec![known_peer(peer_id, 1)]);
+        state.merge_known_peers(vec![known_peer(peer_id, 2)]);
+
+        let candidate_pool = state.get_candidate_pool();
+        assert_eq!(candidate_pool.len(), 1);
+        assert_eq!(candidate_pool[0].freshness_timestamp_usecs, 2);
+    }
+
+    #[test]
+    fn enforce_candidate_pool_size_evicts_the_stalest_entries() {
+        let mut state = KnownPeersState::new(NodeConfig::default(), TimeService::mock());
+
+        let known_peers: Vec<KnownPeerInfo> = (0..(MAX_CANDIDATE_POOL_SIZE + 10) as u64)
+            .map(|i| known_peer(PeerId::random(), i))
+            .collect();
+        state.merge_known_peers(known_peers);
+
+        assert_eq!(state.get_candidate_pool().len(), MAX_CANDIDATE_POOL_SIZE);
+        assert!(state
+            .get_candidate_pool()
+            .iter()
+            .all(|known_peer| known_peer.freshness_timestamp_usecs >= 10));
+    }
+
+    #[test]
+    fn remove_stale_candidates_drops_entries_past_the_staleness_window() {
+        let time_service = TimeService::mock();
+        let mut state = KnownPeersState::new(NodeConfig::default(), time_service.clone());
+
+        let stale_peer_id = PeerId::random();
+        let fresh_peer_id = PeerId::random();
+        state.merge_known_peers(vec![known_peer(stale_peer_id, 0)]);
+
+        // Advance time past the staleness window and merge in a fresh peer
+        let staleness_usecs = CANDIDATE_STALENESS_SECS * 1_000_000;
+        time_service
+            .into_mock()
+            .advance_ms((staleness_usecs / 1_000) + 1);
+        state.merge_known_peers(vec![known_peer(fresh_peer_id, staleness_usecs + 1)]);
+
+        let candidate_pool = state.get_candidate_pool();
+        assert_eq!(candidate_pool.len(), 1);
+        assert_eq!(candidate_pool[0].peer_id, fresh_peer_id);
+    }
+}
+
+impl StateValueInterface for KnownPeersState {
+    fn create_monitoring_service_request(&self) -> PeerMonitoringServiceRequest {
+        PeerMonitoringServiceRequest::GetKnownPeers
+    }
+
+    fn get_request_timeout_ms(&self) -> u64 {
+        self.request_timeout_ms
+    }
+
+    fn get_request_tracker(&self) -> Arc<RwLock<RequestTracker>> {
+        self.request_tracker.clone()
+    }
+
+    fn handle_monitoring_service_response(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _peer_metadata: PeerMetadata,
+        _monitoring_service_request: PeerMonitoringServiceRequest,
+        monitoring_service_response: PeerMonitoringServiceResponse,
+        _response_time_secs: f64,
+    ) {
+        if let PeerMonitoringServiceResponse::KnownPeers(known_peers_response) =
+            monitoring_service_response
+        {
+            self.merge_known_peers(known_peers_response.known_peers);
+        }
+    }
+
+    fn handle_monitoring_service_response_error(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _error: Error,
+    ) {
+        // Nothing to do: we simply retain the last known candidate pool
+    }
+}
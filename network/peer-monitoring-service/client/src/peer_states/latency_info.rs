@@ -0,0 +1,241 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    peer_states::{key_value::StateValueInterface, request_tracker::RequestTracker},
+    Error,
+};
+use aptos_config::{config::NodeConfig, network_id::PeerNetworkId};
+use aptos_infallible::RwLock;
+use aptos_network::application::metadata::PeerMetadata;
+use aptos_peer_monitoring_service_types::{
+    request::{LatencyPingRequest, PeerMonitoringServiceRequest},
+    response::{LatencyPercentiles, LatencyPingResponse, PeerMonitoringServiceResponse},
+};
+use aptos_time_service::TimeService;
+use std::{collections::VecDeque, sync::Arc};
+
+/// The maximum number of recent ping latencies retained in the sliding window
+const MAX_RECENT_PING_LATENCIES: usize = 100;
+
+/// The smoothing factor used for the exponentially weighted moving average.
+/// Higher values weight recent samples more heavily (and decay stale ones faster).
+const EWMA_ALPHA: f64 = 0.2;
+
+/// The state tracked for outstanding and completed latency ping requests
+#[derive(Clone, Debug)]
+pub struct LatencyInfoState {
+    ewma_latency_secs: Option<f64>, // The exponentially weighted moving average of recent ping latencies
+    ping_counter: u64,      // The monotonically increasing counter used for each ping request
+    recent_latency_pings_secs: VecDeque<f64>, // A bounded sliding window of recent (successful) ping latencies
+    recorded_latency_pings_secs: Vec<f64>, // The durations of all recorded (successful) latency pings
+    request_timeout_ms: u64, // The timeout for latency ping requests
+    request_tracker: Arc<RwLock<RequestTracker>>, // The tracker for latency ping requests
+}
+
+impl LatencyInfoState {
+    pub fn new(node_config: NodeConfig, time_service: TimeService) -> Self {
+        let monitoring_service_config = node_config.peer_monitoring_service;
+        let request_tracker = RequestTracker::new(
+            time_service,
+            monitoring_service_config.latency_ping_interval_ms,
+        );
+
+        Self {
+            ewma_latency_secs: None,
+            ping_counter: 0,
+            recent_latency_pings_secs: VecDeque::with_capacity(MAX_RECENT_PING_LATENCIES),
+            recorded_latency_pings_secs: vec![],
+            request_timeout_ms: monitoring_service_config.latency_ping_timeout_ms,
+            request_tracker: Arc::new(RwLock::new(request_tracker)),
+        }
+    }
+
+    /// Records a new latency ping duration
+    fn record_latency_ping(&mut self, latency_ping_secs: f64) {
+        self.recorded_latency_pings_secs.push(latency_ping_secs);
+
+        // Maintain the bounded sliding window of recent pings
+        if self.recent_latency_pings_secs.len() >= MAX_RECENT_PING_LATENCIES {
+            self.recent_latency_pings_secs.pop_front();
+        }
+        self.recent_latency_pings_secs.push_back(latency_ping_secs);
+
+        // Update the exponentially weighted moving average
+        self.ewma_latency_secs = Some(match self.ewma_latency_secs {
+            Some(ewma) => EWMA_ALPHA * latency_ping_secs + (1.0 - EWMA_ALPHA) * ewma,
+            None => latency_ping_secs,
+        });
+    }
+
+    /// Returns the average latency ping duration (in seconds), if any pings have been recorded
+    pub fn get_average_latency_ping_secs(&self) -> Option<f64> {
+        if self.recorded_latency_pings_secs.is_empty() {
+            return None;
+        }
+
+        let sum: f64 = self.recorded_latency_pings_secs.iter().sum();
+        Some(sum / self.recorded_latency_pings_secs.len() as f64)
+    }
+
+    /// Returns the exponentially weighted moving average of recent ping latencies
+    pub fn get_ewma_latency_secs(&self) -> Option<f64> {
+        self.ewma_latency_secs
+    }
+
+    /// Warm-starts the latency history from a previously persisted summary
+    pub fn warm_start(&mut self, average_latency_secs: Option<f64>, ewma_latency_secs: Option<f64>) {
+        if let Some(average_latency_secs) = average_latency_secs {
+            self.recorded_latency_pings_secs.push(average_latency_secs);
+        }
+        if let Some(ewma_latency_secs) = ewma_latency_secs {
+            self.ewma_latency_secs = Some(ewma_latency_secs);
+        }
+    }
+
+    /// Returns the p50/p90/p99 latency percentiles over the sliding window of
+    /// recent ping latencies, if any pings have been recorded
+    pub fn get_latency_percentiles_secs(&self) -> Option<LatencyPercentiles> {
+        if self.recent_latency_pings_secs.is_empty() {
+            return None;
+        }
+
+        let mut sorted_latencies: Vec<f64> = self.recent_latency_pings_secs.iter().copied().collect();
+        sorted_latencies.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // Index-based percentile (no interpolation): the p-th percentile is the
+        // value at the floor((len - 1) * p) index of the sorted sample window
+        let percentile = |p: f64| -> f64 {
+            let index = ((sorted_latencies.len() - 1) as f64 * p).floor() as usize;
+            sorted_latencies[index]
+        };
+
+        Some(LatencyPercentiles {
+            p50_secs: percentile(0.50),
+            p90_secs: percentile(0.90),
+            p99_secs: percentile(0.99),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_pings_yields_no_latency_stats() {
+        let state = LatencyInfoState::new(NodeConfig::default(), TimeService::mock());
+        assert_eq!(state.get_average_latency_ping_secs(), None);
+        assert_eq!(state.get_ewma_latency_secs(), None);
+        assert!(state.get_latency_percentiles_secs().is_none());
+    }
+
+    #[test]
+    fn ewma_weights_recent_samples_more_heavily() {
+        let mut state = LatencyInfoState::new(NodeConfig::default(), TimeService::mock());
+
+        state.record_latency_ping(1.0);
+        assert_eq!(state.get_ewma_latency_secs(), Some(1.0));
+
+        state.record_latency_ping(2.0);
+        let expected = EWMA_ALPHA * 2.0 + (1.0 - EWMA_ALPHA) * 1.0;
+        assert_eq!(state.get_ewma_latency_secs(), Some(expected));
+    }
+
+    #[test]
+    fn average_latency_is_the_mean_of_all_recorded_pings() {
+        let mut state = LatencyInfoState::new(NodeConfig::default(), TimeService::mock());
+
+        for latency_secs in [1.0, 2.0, 3.0] {
+            state.record_latency_ping(latency_secs);
+        }
+
+        assert_eq!(state.get_average_latency_ping_secs(), Some(2.0));
+    }
+
+    #[test]
+    fn percentiles_are_computed_over_the_sliding_window() {
+        let mut state = LatencyInfoState::new(NodeConfig::default(), TimeService::mock());
+
+        for latency_secs in 1..=100 {
+            state.record_latency_ping(latency_secs as f64);
+        }
+
+        let percentiles = state.get_latency_percentiles_secs().unwrap();
+        assert_eq!(percentiles.p50_secs, 50.0);
+        assert_eq!(percentiles.p90_secs, 90.0);
+        assert_eq!(percentiles.p99_secs, 99.0);
+    }
+
+    #[test]
+    fn sliding_window_evicts_the_oldest_pings_once_full() {
+        let mut state = LatencyInfoState::new(NodeConfig::default(), TimeService::mock());
+
+        // Fill the window, then push one more sample past its capacity
+        for latency_secs in 1..=MAX_RECENT_PING_LATENCIES {
+            state.record_latency_ping(latency_secs as f64);
+        }
+        state.record_latency_ping(1000.0);
+
+        // The oldest sample (1.0) should have been evicted from the window,
+        // but the all-time average should still include it
+        let candidate_pool_max = state
+            .recent_latency_pings_secs
+            .iter()
+            .cloned()
+            .fold(f64::MIN, f64::max);
+        assert_eq!(candidate_pool_max, 1000.0); // The newest sample is still in the window
+        assert!(!state.recent_latency_pings_secs.contains(&1.0)); // The oldest sample was evicted
+        assert_eq!(state.get_average_latency_ping_secs(), Some((5050.0 + 1000.0) / 101.0)); // All 101 pings, including 1.0
+    }
+}
+
+impl StateValueInterface for LatencyInfoState {
+    fn create_monitoring_service_request(&self) -> PeerMonitoringServiceRequest {
+        PeerMonitoringServiceRequest::GetLatencyPing(LatencyPingRequest {
+            ping_counter: self.ping_counter,
+        })
+    }
+
+    fn get_request_timeout_ms(&self) -> u64 {
+        self.request_timeout_ms
+    }
+
+    fn get_request_tracker(&self) -> Arc<RwLock<RequestTracker>> {
+        self.request_tracker.clone()
+    }
+
+    fn handle_monitoring_service_response(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _peer_metadata: PeerMetadata,
+        monitoring_service_request: PeerMonitoringServiceRequest,
+        monitoring_service_response: PeerMonitoringServiceResponse,
+        response_time_secs: f64,
+    ) {
+        // Verify the response matches the expected ping counter
+        if let PeerMonitoringServiceRequest::GetLatencyPing(latency_ping_request) =
+            monitoring_service_request
+        {
+            if let PeerMonitoringServiceResponse::LatencyPing(LatencyPingResponse {
+                ping_counter,
+            }) = monitoring_service_response
+            {
+                if ping_counter == latency_ping_request.ping_counter {
+                    self.record_latency_ping(response_time_secs);
+                }
+            }
+        }
+
+        // Update the ping counter and request state
+        self.ping_counter += 1;
+    }
+
+    fn handle_monitoring_service_response_error(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _error: Error,
+    ) {
+        self.ping_counter += 1;
+    }
+}
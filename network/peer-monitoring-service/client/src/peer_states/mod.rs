@@ -0,0 +1,15 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod connection_health;
+pub mod key_value;
+pub mod known_peers;
+pub mod latency_info;
+pub mod network_info;
+pub mod node_info;
+#[cfg(feature = "network-perf-test")] // Disabled by default
+pub mod performance_monitoring;
+pub mod peer_state;
+pub mod request_tracker;
+
+pub use peer_state::PeerState;
@@ -0,0 +1,100 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    peer_states::{key_value::StateValueInterface, request_tracker::RequestTracker},
+    Error,
+};
+use aptos_config::{config::NodeConfig, network_id::PeerNetworkId};
+use aptos_infallible::RwLock;
+use aptos_network::application::metadata::PeerMetadata;
+use aptos_peer_monitoring_service_types::{
+    request::PeerMonitoringServiceRequest,
+    response::{NetworkInformationResponse, PeerMonitoringServiceResponse},
+};
+use aptos_time_service::TimeService;
+use std::sync::Arc;
+
+/// The state tracked for outstanding and completed network info requests
+#[derive(Clone, Debug)]
+pub struct NetworkInfoState {
+    latest_network_info_response: Option<NetworkInformationResponse>, // The latest network info response
+    request_timeout_ms: u64, // The timeout for network info requests
+    request_tracker: Arc<RwLock<RequestTracker>>, // The tracker for network info requests
+    warm_started_distance_from_validators: Option<u64>, // The distance restored from a persisted summary
+}
+
+impl NetworkInfoState {
+    pub fn new(node_config: NodeConfig, time_service: TimeService) -> Self {
+        let monitoring_service_config = node_config.peer_monitoring_service;
+        let request_tracker = RequestTracker::new(
+            time_service,
+            monitoring_service_config.network_info_request_interval_ms,
+        );
+
+        Self {
+            latest_network_info_response: None,
+            request_timeout_ms: monitoring_service_config.network_info_request_timeout_ms,
+            request_tracker: Arc::new(RwLock::new(request_tracker)),
+            warm_started_distance_from_validators: None,
+        }
+    }
+
+    /// Returns the latest network info response (if any)
+    pub fn get_latest_network_info_response(&self) -> Option<NetworkInformationResponse> {
+        self.latest_network_info_response.clone()
+    }
+
+    /// Warm-starts the distance from the validator set from a previously
+    /// persisted summary. This is superseded as soon as a real network info
+    /// response is received.
+    pub fn warm_start(&mut self, distance_from_validators: Option<u64>) {
+        self.warm_started_distance_from_validators = distance_from_validators;
+    }
+
+    /// Returns the peer's distance from the validator set, preferring the
+    /// latest network info response (if any) over the warm-started value
+    pub fn get_distance_from_validators(&self) -> Option<u64> {
+        self.latest_network_info_response
+            .as_ref()
+            .map(|response| response.distance_from_validators)
+            .or(self.warm_started_distance_from_validators)
+    }
+}
+
+impl StateValueInterface for NetworkInfoState {
+    fn create_monitoring_service_request(&self) -> PeerMonitoringServiceRequest {
+        PeerMonitoringServiceRequest::GetNetworkInformation
+    }
+
+    fn get_request_timeout_ms(&self) -> u64 {
+        self.request_timeout_ms
+    }
+
+    fn get_request_tracker(&self) -> Arc<RwLock<RequestTracker>> {
+        self.request_tracker.clone()
+    }
+
+    fn handle_monitoring_service_response(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _peer_metadata: PeerMetadata,
+        _monitoring_service_request: PeerMonitoringServiceRequest,
+        monitoring_service_response: PeerMonitoringServiceResponse,
+        _response_time_secs: f64,
+    ) {
+        if let PeerMonitoringServiceResponse::NetworkInformation(network_info_response) =
+            monitoring_service_response
+        {
+            self.latest_network_info_response = Some(network_info_response);
+        }
+    }
+
+    fn handle_monitoring_service_response_error(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _error: Error,
+    ) {
+        // Nothing to do: we simply retain the last known network info response
+    }
+}
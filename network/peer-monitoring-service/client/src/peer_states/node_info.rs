@@ -0,0 +1,89 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    peer_states::{key_value::StateValueInterface, request_tracker::RequestTracker},
+    Error,
+};
+use aptos_config::{config::NodeConfig, network_id::PeerNetworkId};
+use aptos_infallible::RwLock;
+use aptos_network::application::metadata::PeerMetadata;
+use aptos_peer_monitoring_service_types::{
+    request::PeerMonitoringServiceRequest,
+    response::{NodeInformationResponse, PeerMonitoringServiceResponse},
+};
+use aptos_time_service::TimeService;
+use std::sync::Arc;
+
+/// The state tracked for outstanding and completed node info requests
+#[derive(Clone, Debug)]
+pub struct NodeInfoState {
+    latest_node_info_response: Option<NodeInformationResponse>, // The latest node info response
+    request_timeout_ms: u64, // The timeout for node info requests
+    request_tracker: Arc<RwLock<RequestTracker>>, // The tracker for node info requests
+}
+
+impl NodeInfoState {
+    pub fn new(node_config: NodeConfig, time_service: TimeService) -> Self {
+        let monitoring_service_config = node_config.peer_monitoring_service;
+        let request_tracker = RequestTracker::new(
+            time_service,
+            monitoring_service_config.node_info_request_interval_ms,
+        );
+
+        Self {
+            latest_node_info_response: None,
+            request_timeout_ms: monitoring_service_config.node_info_request_timeout_ms,
+            request_tracker: Arc::new(RwLock::new(request_tracker)),
+        }
+    }
+
+    /// Returns the latest node info response (if any)
+    pub fn get_latest_node_info_response(&self) -> Option<NodeInformationResponse> {
+        self.latest_node_info_response.clone()
+    }
+
+    /// Warm-starts the latest node info response from a previously persisted summary
+    pub fn warm_start(&mut self, node_info_response: Option<NodeInformationResponse>) {
+        if node_info_response.is_some() {
+            self.latest_node_info_response = node_info_response;
+        }
+    }
+}
+
+impl StateValueInterface for NodeInfoState {
+    fn create_monitoring_service_request(&self) -> PeerMonitoringServiceRequest {
+        PeerMonitoringServiceRequest::GetNodeInformation
+    }
+
+    fn get_request_timeout_ms(&self) -> u64 {
+        self.request_timeout_ms
+    }
+
+    fn get_request_tracker(&self) -> Arc<RwLock<RequestTracker>> {
+        self.request_tracker.clone()
+    }
+
+    fn handle_monitoring_service_response(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _peer_metadata: PeerMetadata,
+        _monitoring_service_request: PeerMonitoringServiceRequest,
+        monitoring_service_response: PeerMonitoringServiceResponse,
+        _response_time_secs: f64,
+    ) {
+        if let PeerMonitoringServiceResponse::NodeInformation(node_info_response) =
+            monitoring_service_response
+        {
+            self.latest_node_info_response = Some(node_info_response);
+        }
+    }
+
+    fn handle_monitoring_service_response_error(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _error: Error,
+    ) {
+        // Nothing to do: we simply retain the last known node info response
+    }
+}
@@ -4,12 +4,15 @@
 use crate::{
     metrics, network,
     peer_states::{
+        connection_health::ConnectionHealthState,
         key_value::{PeerStateKey, PeerStateValue, StateValueInterface},
+        known_peers::KnownPeersState,
         latency_info::LatencyInfoState,
         network_info::NetworkInfoState,
         node_info::NodeInfoState,
         request_tracker::RequestTracker,
     },
+    persistence::{PersistedPeerSummary, PersistentPeerMonitoringStore},
     Error, PeerMonitoringServiceClient,
 };
 use aptos_config::{
@@ -20,11 +23,16 @@ use aptos_id_generator::{IdGenerator, U64IdGenerator};
 use aptos_infallible::RwLock;
 use aptos_network::application::{interface::NetworkClient, metadata::PeerMetadata};
 use aptos_peer_monitoring_service_types::{
-    response::PeerMonitoringServiceResponse, PeerMonitoringMetadata, PeerMonitoringServiceMessage,
+    response::{ConnectionHealthStatus, PeerMonitoringServiceResponse},
+    PeerMonitoringMetadata, PeerMonitoringServiceMessage,
 };
 use aptos_time_service::{TimeService, TimeServiceTrait};
 use rand::{rngs::OsRng, Rng};
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, UNIX_EPOCH},
+};
 use tokio::{runtime::Handle, task::JoinHandle, time::sleep};
 
 #[derive(Clone, Debug)]
@@ -44,9 +52,93 @@ impl PeerState {
                 .insert(peer_state_key, Arc::new(RwLock::new(peer_state_value)));
         }
 
+        // Create the connection health entry separately. Unlike the other state
+        // entries, it isn't refreshed by `PeerStateKey::get_all_keys` (it has no
+        // wire request of its own), so it's inserted directly here instead.
+        let connection_health_value =
+            PeerStateValue::new(node_config, time_service, &PeerStateKey::ConnectionHealth);
+        state_entries.write().insert(
+            PeerStateKey::ConnectionHealth,
+            Arc::new(RwLock::new(connection_health_value)),
+        );
+
         Self { state_entries }
     }
 
+    /// Creates a new `PeerState`, warm-starting the latency info, node info,
+    /// network info and connection-health state entries from a previously persisted
+    /// summary (if any). This allows accumulated peer reputation to survive
+    /// node restarts, rather than starting from a completely blank state.
+    pub fn new_with_persisted_state(
+        node_config: NodeConfig,
+        time_service: TimeService,
+        persisted_summary: Option<PersistedPeerSummary>,
+    ) -> Self {
+        let peer_state = Self::new(node_config, time_service);
+        if let Some(persisted_summary) = persisted_summary {
+            peer_state.warm_start_from_persisted_summary(persisted_summary);
+        }
+        peer_state
+    }
+
+    /// Applies a previously persisted summary to the relevant state entries
+    fn warm_start_from_persisted_summary(&self, persisted_summary: PersistedPeerSummary) {
+        if let Ok(node_info_value) = self.get_peer_state_value(&PeerStateKey::NodeInfo) {
+            if let PeerStateValue::NodeInfoState(node_info_state) = &mut *node_info_value.write() {
+                node_info_state.warm_start(persisted_summary.latest_node_info_response);
+            }
+        }
+
+        if let Ok(latency_info_value) = self.get_peer_state_value(&PeerStateKey::LatencyInfo) {
+            if let PeerStateValue::LatencyInfoState(latency_info_state) =
+                &mut *latency_info_value.write()
+            {
+                latency_info_state.warm_start(
+                    persisted_summary.average_latency_secs,
+                    persisted_summary.ewma_latency_secs,
+                );
+            }
+        }
+
+        if let Ok(connection_health_value) =
+            self.get_peer_state_value(&PeerStateKey::ConnectionHealth)
+        {
+            if let PeerStateValue::ConnectionHealthState(connection_health_state) =
+                &mut *connection_health_value.write()
+            {
+                connection_health_state.warm_start(persisted_summary.consecutive_failures);
+            }
+        }
+
+        if let Ok(network_info_value) = self.get_peer_state_value(&PeerStateKey::NetworkInfo) {
+            if let PeerStateValue::NetworkInfoState(network_info_state) =
+                &mut *network_info_value.write()
+            {
+                network_info_state.warm_start(persisted_summary.distance_from_validators);
+            }
+        }
+    }
+
+    /// Builds a persistable summary of this peer's current monitoring state,
+    /// suitable for snapshotting via a `PersistentPeerMonitoringStore`
+    pub fn to_persisted_summary(&self, now_secs: u64) -> Result<PersistedPeerSummary, Error> {
+        let latency_info_state = self.get_latency_info_state()?;
+        let node_info_state = self.get_node_info_state()?;
+        let network_info_state = self.get_network_info_state()?;
+        let connection_health_state = self.get_connection_health_state()?;
+
+        Ok(PersistedPeerSummary {
+            average_latency_secs: latency_info_state.get_average_latency_ping_secs(),
+            ewma_latency_secs: latency_info_state.get_ewma_latency_secs(),
+            latest_node_info_response: node_info_state.get_latest_node_info_response(),
+            consecutive_failures: connection_health_state
+                .get_connection_health()
+                .consecutive_failures,
+            distance_from_validators: network_info_state.get_distance_from_validators(),
+            last_updated_secs: now_secs,
+        })
+    }
+
     /// Returns the request tracker for the given peer state key
     pub fn get_request_tracker(
         &self,
@@ -56,6 +148,42 @@ impl PeerState {
             .map(|peer_state_value| peer_state_value.read().get_request_tracker())
     }
 
+    /// Records the outcome of a monitoring request against the peer's rolling
+    /// connection-health state. This is independent of which `PeerStateKey` the
+    /// request was made for: every completed request (successful or not) feeds
+    /// the same connection-health aggregate.
+    fn record_connection_health_outcome(&self, request_succeeded: bool) -> Result<(), Error> {
+        let peer_state_value = self.get_peer_state_value(&PeerStateKey::ConnectionHealth)?;
+        if let PeerStateValue::ConnectionHealthState(connection_health_state) =
+            &mut *peer_state_value.write()
+        {
+            if request_succeeded {
+                connection_health_state.record_success();
+            } else {
+                connection_health_state.record_failure();
+            }
+        }
+        Ok(())
+    }
+
+    /// Snapshots this peer's current monitoring state and saves it via the
+    /// given store (if one is configured). Called after every completed
+    /// request so that a persisted backend stays up to date without needing
+    /// its own separate polling loop.
+    fn flush_persisted_summary(
+        &self,
+        persistent_store: &Option<Arc<dyn PersistentPeerMonitoringStore>>,
+        peer_network_id: &PeerNetworkId,
+        time_service: &TimeService,
+    ) {
+        if let Some(persistent_store) = persistent_store {
+            let now_secs = time_service.now().duration_since(UNIX_EPOCH).as_secs();
+            if let Ok(persisted_summary) = self.to_persisted_summary(now_secs) {
+                let _ = persistent_store.save(peer_network_id.peer_id(), persisted_summary);
+            }
+        }
+    }
+
     /// Refreshes the peer state key by sending a request to the peer
     pub fn refresh_peer_state_key(
         &self,
@@ -69,6 +197,7 @@ impl PeerState {
         request_id_generator: Arc<U64IdGenerator>,
         time_service: TimeService,
         runtime: Option<Handle>,
+        persistent_store: Option<Arc<dyn PersistentPeerMonitoringStore>>,
     ) -> Result<JoinHandle<()>, Error> {
         // Mark the request as having started. We do this here to prevent
         // the monitor loop from selecting the same peer state key concurrently.
@@ -87,6 +216,10 @@ impl PeerState {
         // Get the max message size for the response
         let max_num_response_bytes = monitoring_service_config.max_num_response_bytes;
 
+        // Clone the peer state so the connection-health outcome can be recorded
+        // once the request completes, regardless of which state key was refreshed
+        let peer_state = self.clone();
+
         // Create the request task
         let request_task = async move {
             // Add some amount of jitter before sending the request.
@@ -120,6 +253,13 @@ impl PeerState {
                     peer_state_value
                         .write()
                         .handle_monitoring_service_response_error(&peer_network_id, error);
+                    request_tracker.write().request_failed();
+                    let _ = peer_state.record_connection_health_outcome(false);
+                    peer_state.flush_persisted_summary(
+                        &persistent_store,
+                        &peer_network_id,
+                        &time_service,
+                    );
                     return;
                 },
             };
@@ -131,6 +271,13 @@ impl PeerState {
                 peer_state_value
                     .write()
                     .handle_monitoring_service_response_error(&peer_network_id, error);
+                request_tracker.write().request_failed();
+                let _ = peer_state.record_connection_health_outcome(false);
+                peer_state.flush_persisted_summary(
+                    &persistent_store,
+                    &peer_network_id,
+                    &time_service,
+                );
                 return;
             }
 
@@ -143,13 +290,78 @@ impl PeerState {
                 request_duration_secs,
             );
 
+            // Record the successful outcome against the request tracker (resetting
+            // any backoff) and the connection-health state
+            request_tracker.write().request_succeeded();
+            let _ = peer_state.record_connection_health_outcome(true);
+            let connection_health_status = peer_state
+                .get_connection_health_state()
+                .map(|state| state.get_status())
+                .unwrap_or(ConnectionHealthStatus::Unreachable);
+
             // Update the latency ping metrics
             metrics::observe_value(
                 &metrics::REQUEST_LATENCIES,
                 monitoring_service_request.get_label(),
                 &peer_network_id,
+                connection_health_status.as_str(),
                 request_duration_secs,
             );
+
+            // Update the EWMA and sliding-window percentile latency metrics
+            if let Ok(latency_info_state) = peer_state.get_latency_info_state() {
+                if let Some(ewma_latency_secs) = latency_info_state.get_ewma_latency_secs() {
+                    metrics::set_gauge(&metrics::LATENCY_PING_EWMA_SECS, &peer_network_id, ewma_latency_secs);
+                }
+                if let Some(latency_percentiles) = latency_info_state.get_latency_percentiles_secs() {
+                    metrics::set_percentile_gauge(
+                        &metrics::LATENCY_PING_PERCENTILE_SECS,
+                        &peer_network_id,
+                        "p50",
+                        latency_percentiles.p50_secs,
+                    );
+                    metrics::set_percentile_gauge(
+                        &metrics::LATENCY_PING_PERCENTILE_SECS,
+                        &peer_network_id,
+                        "p90",
+                        latency_percentiles.p90_secs,
+                    );
+                    metrics::set_percentile_gauge(
+                        &metrics::LATENCY_PING_PERCENTILE_SECS,
+                        &peer_network_id,
+                        "p99",
+                        latency_percentiles.p99_secs,
+                    );
+                }
+            }
+
+            // Update the rolling upload/download goodput (bandwidth) metrics
+            #[cfg(feature = "network-perf-test")] // Disabled by default
+            if let Ok(performance_monitoring_state) = peer_state.get_performance_monitoring_state() {
+                if let Some(upload_goodput_bytes_per_sec) =
+                    performance_monitoring_state.get_rolling_upload_bytes_per_sec()
+                {
+                    metrics::set_goodput_gauge(
+                        &metrics::PEER_GOODPUT_BYTES_PER_SEC,
+                        &peer_network_id,
+                        "upload",
+                        upload_goodput_bytes_per_sec,
+                    );
+                }
+                if let Some(download_goodput_bytes_per_sec) =
+                    performance_monitoring_state.get_rolling_download_bytes_per_sec()
+                {
+                    metrics::set_goodput_gauge(
+                        &metrics::PEER_GOODPUT_BYTES_PER_SEC,
+                        &peer_network_id,
+                        "download",
+                        download_goodput_bytes_per_sec,
+                    );
+                }
+            }
+
+            // Snapshot the peer's monitoring state via the persistent store (if any)
+            peer_state.flush_persisted_summary(&persistent_store, &peer_network_id, &time_service);
         };
 
         // Spawn the request task
@@ -167,10 +379,13 @@ impl PeerState {
         // Create an empty metadata entry for the peer
         let mut peer_monitoring_metadata = PeerMonitoringMetadata::default();
 
-        // Get and store the average latency ping
+        // Get and store the average, EWMA and percentile latency pings
         let latency_info_state = self.get_latency_info_state()?;
-        let average_latency_ping_secs = latency_info_state.get_average_latency_ping_secs();
-        peer_monitoring_metadata.average_ping_latency_secs = average_latency_ping_secs;
+        peer_monitoring_metadata.average_ping_latency_secs =
+            latency_info_state.get_average_latency_ping_secs();
+        peer_monitoring_metadata.ewma_ping_latency_secs = latency_info_state.get_ewma_latency_secs();
+        peer_monitoring_metadata.latency_percentiles =
+            latency_info_state.get_latency_percentiles_secs();
 
         // Get and store the latest network info response
         let network_info_state = self.get_network_info_state()?;
@@ -182,6 +397,15 @@ impl PeerState {
         let node_info_response = node_info_state.get_latest_node_info_response();
         peer_monitoring_metadata.latest_node_info_response = node_info_response;
 
+        // Get and store the connection health summary
+        let connection_health_state = self.get_connection_health_state()?;
+        peer_monitoring_metadata.connection_health =
+            Some(connection_health_state.get_connection_health());
+
+        // Get and store the candidate pool of gossiped known peers
+        let known_peers_state = self.get_known_peers_state()?;
+        peer_monitoring_metadata.known_peers = known_peers_state.get_candidate_pool();
+
         Ok(peer_monitoring_metadata)
     }
 
@@ -199,6 +423,23 @@ impl PeerState {
         })
     }
 
+    /// Returns a copy of the connection health state
+    pub(crate) fn get_connection_health_state(&self) -> Result<ConnectionHealthState, Error> {
+        let peer_state_value = self
+            .get_peer_state_value(&PeerStateKey::ConnectionHealth)?
+            .read()
+            .clone();
+        match peer_state_value {
+            PeerStateValue::ConnectionHealthState(connection_health_state) => {
+                Ok(connection_health_state)
+            },
+            peer_state_value => Err(Error::UnexpectedError(format!(
+                "Invalid peer state value found! Expected connection_health_state but got: {:?}",
+                peer_state_value
+            ))),
+        }
+    }
+
     /// Returns a copy of the latency ping state
     pub(crate) fn get_latency_info_state(&self) -> Result<LatencyInfoState, Error> {
         let peer_state_value = self
@@ -214,6 +455,21 @@ impl PeerState {
         }
     }
 
+    /// Returns a copy of the known peers state
+    pub(crate) fn get_known_peers_state(&self) -> Result<KnownPeersState, Error> {
+        let peer_state_value = self
+            .get_peer_state_value(&PeerStateKey::KnownPeers)?
+            .read()
+            .clone();
+        match peer_state_value {
+            PeerStateValue::KnownPeersState(known_peers_state) => Ok(known_peers_state),
+            peer_state_value => Err(Error::UnexpectedError(format!(
+                "Invalid peer state value found! Expected known_peers_state but got: {:?}",
+                peer_state_value
+            ))),
+        }
+    }
+
     /// Returns a copy of the network info state
     pub(crate) fn get_network_info_state(&self) -> Result<NetworkInfoState, Error> {
         let peer_state_value = self
@@ -0,0 +1,255 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    peer_states::{key_value::StateValueInterface, request_tracker::RequestTracker},
+    Error,
+};
+use aptos_config::{config::NodeConfig, network_id::PeerNetworkId};
+use aptos_infallible::RwLock;
+use aptos_network::application::metadata::PeerMetadata;
+use aptos_peer_monitoring_service_types::{
+    request::{PeerMonitoringServiceRequest, PerformanceMonitoringRequest},
+    response::{PeerMonitoringServiceResponse, PerformanceMonitoringResponse},
+};
+use aptos_time_service::TimeService;
+use std::sync::Arc;
+
+/// The default size (in bytes) of the padded request/response payloads used
+/// for each performance probe, before being clamped to the configured
+/// `max_num_response_bytes` limit.
+const DEFAULT_PROBE_PAYLOAD_BYTES: u64 = 1024 * 1024; // 1 MiB
+
+/// The overhead (in bytes) added by bcs when serializing a
+/// `PerformanceMonitoringResponse`: the `PeerMonitoringServiceResponse` enum
+/// variant tag, the `response_counter` field, and the `Vec<u8>` length
+/// prefix for `response_payload`. This is subtracted from
+/// `max_num_response_bytes` before clamping, so the fully serialized
+/// response (not just the payload) stays within the server's limit.
+const RESPONSE_SERIALIZATION_OVERHEAD_BYTES: u64 = 16;
+
+/// The smoothing factor used for the rolling goodput (bandwidth) estimates
+const GOODPUT_EWMA_ALPHA: f64 = 0.2;
+
+/// The state tracked for outstanding and completed performance monitoring
+/// (i.e., throughput/bandwidth) requests
+#[derive(Clone, Debug)]
+pub struct PerformanceMonitoringState {
+    request_counter: u64, // The monotonically increasing counter used for each request
+    request_timeout_ms: u64, // The timeout for performance monitoring requests
+    request_tracker: Arc<RwLock<RequestTracker>>, // The tracker for performance monitoring requests
+    requested_probe_bytes: u64, // The size of the upload/download padding blobs used for each probe
+    rolling_download_bytes_per_sec: Option<f64>, // The rolling (EWMA) download goodput estimate, in bytes/sec
+    rolling_upload_bytes_per_sec: Option<f64>, // The rolling (EWMA) upload goodput estimate, in bytes/sec
+}
+
+impl PerformanceMonitoringState {
+    pub fn new(node_config: NodeConfig, time_service: TimeService) -> Self {
+        let monitoring_service_config = node_config.peer_monitoring_service;
+        let request_tracker = RequestTracker::new(
+            time_service,
+            monitoring_service_config.performance_monitoring_interval_ms,
+        );
+
+        // Never request more than the server is willing to respond with, or a
+        // response that exceeds `max_num_response_bytes` is treated as an
+        // oversized response and counted as a connection-health failure.
+        // The requested payload size is also clamped below
+        // `max_num_response_bytes` by the bcs serialization overhead, since
+        // the full response (not just the payload) is what gets measured
+        // against the limit.
+        let max_probe_payload_bytes = monitoring_service_config
+            .max_num_response_bytes
+            .saturating_sub(RESPONSE_SERIALIZATION_OVERHEAD_BYTES);
+        let requested_probe_bytes = DEFAULT_PROBE_PAYLOAD_BYTES.min(max_probe_payload_bytes);
+
+        Self {
+            request_counter: 0,
+            request_timeout_ms: monitoring_service_config.performance_monitoring_timeout_ms,
+            request_tracker: Arc::new(RwLock::new(request_tracker)),
+            requested_probe_bytes,
+            rolling_download_bytes_per_sec: None,
+            rolling_upload_bytes_per_sec: None,
+        }
+    }
+
+    /// Records a new download goodput sample (bytes/sec), updating the rolling estimate
+    fn record_download_goodput_sample(&mut self, goodput_bytes_per_sec: f64) {
+        self.rolling_download_bytes_per_sec = Some(Self::update_rolling_goodput(
+            self.rolling_download_bytes_per_sec,
+            goodput_bytes_per_sec,
+        ));
+    }
+
+    /// Records a new upload goodput sample (bytes/sec), updating the rolling estimate
+    fn record_upload_goodput_sample(&mut self, goodput_bytes_per_sec: f64) {
+        self.rolling_upload_bytes_per_sec = Some(Self::update_rolling_goodput(
+            self.rolling_upload_bytes_per_sec,
+            goodput_bytes_per_sec,
+        ));
+    }
+
+    /// Folds a new goodput sample into the existing rolling (EWMA) estimate
+    fn update_rolling_goodput(rolling_goodput: Option<f64>, goodput_bytes_per_sec: f64) -> f64 {
+        match rolling_goodput {
+            Some(rolling_goodput) => {
+                GOODPUT_EWMA_ALPHA * goodput_bytes_per_sec
+                    + (1.0 - GOODPUT_EWMA_ALPHA) * rolling_goodput
+            },
+            None => goodput_bytes_per_sec,
+        }
+    }
+
+    /// Returns the peer's rolling download goodput estimate (bytes/sec), if any samples have been recorded
+    pub fn get_rolling_download_bytes_per_sec(&self) -> Option<f64> {
+        self.rolling_download_bytes_per_sec
+    }
+
+    /// Returns the peer's rolling upload goodput estimate (bytes/sec), if any samples have been recorded
+    pub fn get_rolling_upload_bytes_per_sec(&self) -> Option<f64> {
+        self.rolling_upload_bytes_per_sec
+    }
+}
+
+impl StateValueInterface for PerformanceMonitoringState {
+    fn create_monitoring_service_request(&self) -> PeerMonitoringServiceRequest {
+        PeerMonitoringServiceRequest::PerformanceMonitoringRequest(PerformanceMonitoringRequest {
+            request_counter: self.request_counter,
+            request_payload: vec![0; self.requested_probe_bytes as usize],
+            requested_response_bytes: self.requested_probe_bytes,
+        })
+    }
+
+    fn get_request_timeout_ms(&self) -> u64 {
+        self.request_timeout_ms
+    }
+
+    fn get_request_tracker(&self) -> Arc<RwLock<RequestTracker>> {
+        self.request_tracker.clone()
+    }
+
+    fn handle_monitoring_service_response(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _peer_metadata: PeerMetadata,
+        monitoring_service_request: PeerMonitoringServiceRequest,
+        monitoring_service_response: PeerMonitoringServiceResponse,
+        response_time_secs: f64,
+    ) {
+        // Verify the response matches the expected request counter and derive the
+        // observed upload/download goodput from the size of the request/response
+        // padding blobs (both sent over the same round trip)
+        if let PeerMonitoringServiceRequest::PerformanceMonitoringRequest(request) =
+            &monitoring_service_request
+        {
+            if let PeerMonitoringServiceResponse::PerformanceMonitoring(
+                PerformanceMonitoringResponse {
+                    response_counter,
+                    response_payload,
+                },
+            ) = monitoring_service_response
+            {
+                if response_counter == request.request_counter && response_time_secs > 0.0 {
+                    let upload_goodput_bytes_per_sec =
+                        request.request_payload.len() as f64 / response_time_secs;
+                    self.record_upload_goodput_sample(upload_goodput_bytes_per_sec);
+
+                    let download_goodput_bytes_per_sec =
+                        response_payload.len() as f64 / response_time_secs;
+                    self.record_download_goodput_sample(download_goodput_bytes_per_sec);
+                }
+            }
+        }
+
+        // Update the request counter
+        self.request_counter += 1;
+    }
+
+    fn handle_monitoring_service_response_error(
+        &mut self,
+        _peer_network_id: &PeerNetworkId,
+        _error: Error,
+    ) {
+        self.request_counter += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requested_probe_bytes_is_clamped_to_the_max_response_size() {
+        let mut node_config = NodeConfig::default();
+        node_config.peer_monitoring_service.max_num_response_bytes = 1024;
+
+        let state = PerformanceMonitoringState::new(node_config, TimeService::mock());
+        if let PeerMonitoringServiceRequest::PerformanceMonitoringRequest(request) =
+            state.create_monitoring_service_request()
+        {
+            let expected_bytes = 1024 - RESPONSE_SERIALIZATION_OVERHEAD_BYTES;
+            assert_eq!(request.requested_response_bytes, expected_bytes);
+            assert_eq!(request.request_payload.len(), expected_bytes as usize);
+        } else {
+            panic!("Expected a performance monitoring request!");
+        }
+    }
+
+    #[test]
+    fn clamped_probe_response_passes_the_max_response_size_check() {
+        // Use a small max response size so the clamp is exercised
+        let mut node_config = NodeConfig::default();
+        node_config.peer_monitoring_service.max_num_response_bytes = 1024;
+        let max_num_response_bytes = node_config.peer_monitoring_service.max_num_response_bytes;
+
+        let state = PerformanceMonitoringState::new(node_config, TimeService::mock());
+        let requested_response_bytes =
+            if let PeerMonitoringServiceRequest::PerformanceMonitoringRequest(request) =
+                state.create_monitoring_service_request()
+            {
+                request.requested_response_bytes
+            } else {
+                panic!("Expected a performance monitoring request!");
+            };
+
+        // Simulate the server echoing back a response of the requested size,
+        // and verify the fully serialized response still respects the limit
+        let response = PeerMonitoringServiceResponse::PerformanceMonitoring(
+            PerformanceMonitoringResponse {
+                response_counter: 0,
+                response_payload: vec![0; requested_response_bytes as usize],
+            },
+        );
+        assert!(response.get_num_bytes().unwrap() <= max_num_response_bytes);
+    }
+
+    #[test]
+    fn requested_probe_bytes_defaults_below_a_generous_max_response_size() {
+        let node_config = NodeConfig::default();
+        let state = PerformanceMonitoringState::new(node_config, TimeService::mock());
+
+        if let PeerMonitoringServiceRequest::PerformanceMonitoringRequest(request) =
+            state.create_monitoring_service_request()
+        {
+            assert_eq!(request.requested_response_bytes, DEFAULT_PROBE_PAYLOAD_BYTES);
+        } else {
+            panic!("Expected a performance monitoring request!");
+        }
+    }
+
+    #[test]
+    fn upload_and_download_goodput_are_tracked_independently() {
+        let mut state = PerformanceMonitoringState::new(NodeConfig::default(), TimeService::mock());
+
+        state.record_upload_goodput_sample(100.0);
+        state.record_download_goodput_sample(200.0);
+
+        assert_eq!(state.get_rolling_upload_bytes_per_sec(), Some(100.0));
+        assert_eq!(state.get_rolling_download_bytes_per_sec(), Some(200.0));
+
+        state.record_upload_goodput_sample(300.0);
+        let expected_upload = GOODPUT_EWMA_ALPHA * 300.0 + (1.0 - GOODPUT_EWMA_ALPHA) * 100.0;
+        assert_eq!(state.get_rolling_upload_bytes_per_sec(), Some(expected_upload));
+        assert_eq!(state.get_rolling_download_bytes_per_sec(), Some(200.0));
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_time_service::{TimeService, TimeServiceTrait};
+use std::time::{Duration, UNIX_EPOCH};
+
+/// The multiplicative factor applied to the base request interval after each
+/// consecutive failure (i.e., the backoff doubles on every failure)
+const BACKOFF_MULTIPLIER: u64 = 2;
+
+/// The maximum multiple of the base request interval that backoff can reach
+const MAX_BACKOFF_MULTIPLIER: u64 = 16;
+
+/// The number of consecutive failures after which the circuit is opened
+/// (i.e., the peer is skipped entirely until the cooldown window elapses)
+const CIRCUIT_BREAKER_FAILURE_THRESHOLD: u64 = 8;
+
+/// The cooldown window (in milliseconds) a peer is skipped for once its circuit opens
+const CIRCUIT_BREAKER_COOLDOWN_MS: u64 = 60_000;
+
+/// A simple tracker for the current state of requests sent to a peer
+/// (e.g., whether a request is in-flight, and when the next request
+/// should be sent). Implements exponential backoff and circuit-breaking
+/// so that peers experiencing consecutive failures are retried less
+/// aggressively, rather than at the same cadence as a healthy peer.
+#[derive(Clone, Debug)]
+pub struct RequestTracker {
+    circuit_opened_time: Option<Duration>, // The time the circuit was opened (if currently open)
+    consecutive_failures: u64, // The number of consecutive request failures
+    in_flight_request: bool,   // Whether a request is currently in-flight
+    last_request_time: Option<Duration>, // The time of the last request (if any)
+    request_interval_ms: u64,  // The base interval between requests (before backoff)
+    time_service: TimeService, // The time service to use for tracking
+}
+
+impl RequestTracker {
+    pub fn new(time_service: TimeService, request_interval_ms: u64) -> Self {
+        Self {
+            circuit_opened_time: None,
+            consecutive_failures: 0,
+            in_flight_request: false,
+            last_request_time: None,
+            request_interval_ms,
+            time_service,
+        }
+    }
+
+    /// Marks the request as having started
+    pub fn request_started(&mut self) {
+        self.in_flight_request = true;
+        self.last_request_time = Some(self.now());
+    }
+
+    /// Marks the in-flight request as now complete
+    pub fn request_completed(&mut self) {
+        self.in_flight_request = false;
+    }
+
+    /// Records that the completed request succeeded. This resets the
+    /// failure streak, restoring the base request interval, and closes
+    /// the circuit breaker (if open).
+    pub fn request_succeeded(&mut self) {
+        self.consecutive_failures = 0;
+        self.circuit_opened_time = None;
+    }
+
+    /// Records that the completed request failed. This extends the failure
+    /// streak (increasing the effective backoff interval) and opens the
+    /// circuit breaker once the consecutive-failure threshold is crossed.
+    /// If the circuit was previously open but its cooldown has since elapsed,
+    /// it is cleared first, so that continued failures can re-open it.
+    pub fn request_failed(&mut self) {
+        self.consecutive_failures += 1;
+
+        if self.circuit_opened_time.is_some() && !self.is_circuit_open() {
+            self.circuit_opened_time = None;
+        }
+
+        if self.consecutive_failures >= CIRCUIT_BREAKER_FAILURE_THRESHOLD
+            && self.circuit_opened_time.is_none()
+        {
+            self.circuit_opened_time = Some(self.now());
+        }
+    }
+
+    /// Returns true iff a request is currently in-flight
+    pub fn in_flight(&self) -> bool {
+        self.in_flight_request
+    }
+
+    /// Returns the configured base request interval (in milliseconds), i.e.,
+    /// before any backoff is applied
+    pub fn get_request_interval_ms(&self) -> u64 {
+        self.request_interval_ms
+    }
+
+    /// Returns the number of consecutive request failures
+    pub fn get_num_consecutive_failures(&self) -> u64 {
+        self.consecutive_failures
+    }
+
+    /// Returns the effective request interval (in milliseconds), after
+    /// applying exponential backoff for the current failure streak
+    fn get_effective_request_interval_ms(&self) -> u64 {
+        let backoff_multiplier = BACKOFF_MULTIPLIER
+            .saturating_pow(self.consecutive_failures as u32)
+            .min(MAX_BACKOFF_MULTIPLIER);
+        self.request_interval_ms.saturating_mul(backoff_multiplier)
+    }
+
+    /// Returns true iff the circuit breaker is currently open (i.e., the peer
+    /// should be skipped entirely until the cooldown window elapses)
+    fn is_circuit_open(&self) -> bool {
+        match self.circuit_opened_time {
+            Some(opened_time) => {
+                self.now().saturating_sub(opened_time).as_millis() as u64
+                    < CIRCUIT_BREAKER_COOLDOWN_MS
+            },
+            None => false,
+        }
+    }
+
+    /// Returns the duration since the last request was made (if any)
+    fn duration_since_last_request(&self) -> Option<Duration> {
+        let now = self.now();
+        self.last_request_time
+            .map(|last_request_time| now.saturating_sub(last_request_time))
+    }
+
+    /// Returns true iff a new request is ready to be sent. This accounts for
+    /// any in-flight request, the peer's backoff interval (which grows with
+    /// the consecutive-failure streak) and whether the circuit is currently
+    /// open (in which case the peer is skipped until the cooldown elapses).
+    pub fn new_request_ready(&self) -> bool {
+        if self.in_flight_request || self.is_circuit_open() {
+            return false;
+        }
+
+        match self.duration_since_last_request() {
+            Some(duration) => {
+                duration.as_millis() as u64 >= self.get_effective_request_interval_ms()
+            },
+            None => true, // No request has ever been sent
+        }
+    }
+
+    fn now(&self) -> Duration {
+        self.time_service.now().duration_since(UNIX_EPOCH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_and_caps_with_consecutive_failures() {
+        let time_service = TimeService::mock();
+        let mut request_tracker = RequestTracker::new(time_service, 100);
+
+        assert_eq!(request_tracker.get_effective_request_interval_ms(), 100);
+
+        for expected_multiplier in [2, 4, 8, 16, 16, 16] {
+            request_tracker.request_failed();
+            assert_eq!(
+                request_tracker.get_effective_request_interval_ms(),
+                100 * expected_multiplier
+            );
+        }
+    }
+
+    #[test]
+    fn request_succeeded_resets_backoff_and_closes_circuit() {
+        let time_service = TimeService::mock();
+        let mut request_tracker = RequestTracker::new(time_service, 100);
+
+        for _ in 0..CIRCUIT_BREAKER_FAILURE_THRESHOLD {
+            request_tracker.request_failed();
+        }
+        assert!(request_tracker.is_circuit_open());
+
+        request_tracker.request_succeeded();
+        assert_eq!(request_tracker.get_num_consecutive_failures(), 0);
+        assert!(!request_tracker.is_circuit_open());
+    }
+
+    #[test]
+    fn circuit_opens_after_threshold_failures_and_blocks_requests() {
+        let time_service = TimeService::mock();
+        let mut request_tracker = RequestTracker::new(time_service, 100);
+
+        for _ in 0..(CIRCUIT_BREAKER_FAILURE_THRESHOLD - 1) {
+            request_tracker.request_failed();
+            assert!(!request_tracker.is_circuit_open());
+        }
+
+        request_tracker.request_failed();
+        assert!(request_tracker.is_circuit_open());
+        assert!(!request_tracker.new_request_ready());
+    }
+
+    #[test]
+    fn circuit_reopens_after_cooldown_on_continued_failures() {
+        let time_service = TimeService::mock();
+        let mut request_tracker = RequestTracker::new(time_service.clone(), 100);
+
+        for _ in 0..CIRCUIT_BREAKER_FAILURE_THRESHOLD {
+            request_tracker.request_failed();
+        }
+        assert!(request_tracker.is_circuit_open());
+
+        // Advance time past the cooldown window: the circuit should now read as closed
+        time_service
+            .into_mock()
+            .advance_ms(CIRCUIT_BREAKER_COOLDOWN_MS + 1);
+        assert!(!request_tracker.is_circuit_open());
+
+        // A continued failure should re-open the circuit rather than leaving it
+        // permanently cleared
+        request_tracker.request_failed();
+        assert!(request_tracker.is_circuit_open());
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Error;
+use aptos_infallible::RwLock;
+use aptos_peer_monitoring_service_types::response::NodeInformationResponse;
+use aptos_types::PeerId;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A compact, persistable summary of the monitoring state accumulated for a
+/// single peer. This is snapshotted after every completed monitoring request
+/// (see `PeerState::flush_persisted_summary`) so that
+/// restarts can warm-start `PeerState` instead of throwing away all
+/// previously observed latency history, node info and failure streaks.
+///
+/// Note: the sliding-window latency percentiles are deliberately excluded.
+/// They're derived from the raw ping samples in the window, which aren't
+/// themselves persisted, so there's nothing sound to warm-start them from.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PersistedPeerSummary {
+    pub average_latency_secs: Option<f64>,
+    pub ewma_latency_secs: Option<f64>,
+    pub latest_node_info_response: Option<NodeInformationResponse>,
+    pub consecutive_failures: u64,
+    pub distance_from_validators: Option<u64>,
+    pub last_updated_secs: u64, // The unix timestamp (in seconds) this summary was last updated
+}
+
+/// A pluggable backend for persisting peer monitoring summaries across node
+/// restarts. This allows an embedded key-value store or SQLite database to be
+/// wired in, without coupling the monitoring client to a specific storage engine.
+pub trait PersistentPeerMonitoringStore: Send + Sync {
+    /// Loads every persisted peer summary known to the store
+    fn load_all(&self) -> Result<HashMap<PeerId, PersistedPeerSummary>, Error>;
+
+    /// Saves (or overwrites) the persisted summary for the given peer
+    fn save(&self, peer_id: PeerId, summary: PersistedPeerSummary) -> Result<(), Error>;
+
+    /// Evicts all persisted summaries that haven't been updated within the
+    /// last `max_peer_age_secs` (relative to `now_secs`)
+    fn remove_stale_entries(&self, max_peer_age_secs: u64, now_secs: u64) -> Result<(), Error>;
+}
+
+/// A simple, bounded in-memory implementation of `PersistentPeerMonitoringStore`.
+/// This is the default backend: it doesn't survive a process restart on its
+/// own, but other components (e.g., a key-value or SQLite backed store) can
+/// implement the same trait and be wired in without any further changes here.
+pub struct InMemoryPeerMonitoringStore {
+    max_num_peers: usize, // The maximum number of peer summaries retained by the store
+    peer_summaries: RwLock<HashMap<PeerId, PersistedPeerSummary>>,
+}
+
+impl InMemoryPeerMonitoringStore {
+    pub fn new(max_num_peers: usize) -> Self {
+        Self {
+            max_num_peers,
+            peer_summaries: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl PersistentPeerMonitoringStore for InMemoryPeerMonitoringStore {
+    fn load_all(&self) -> Result<HashMap<PeerId, PersistedPeerSummary>, Error> {
+        Ok(self.peer_summaries.read().clone())
+    }
+
+    fn save(&self, peer_id: PeerId, summary: PersistedPeerSummary) -> Result<(), Error> {
+        let mut peer_summaries = self.peer_summaries.write();
+
+        // Evict the oldest entry if we're about to exceed the bounded row count
+        if peer_summaries.len() >= self.max_num_peers && !peer_summaries.contains_key(&peer_id) {
+            if let Some(oldest_peer_id) = peer_summaries
+                .iter()
+                .min_by_key(|(_, summary)| summary.last_updated_secs)
+                .map(|(peer_id, _)| *peer_id)
+            {
+                peer_summaries.remove(&oldest_peer_id);
+            }
+        }
+
+        peer_summaries.insert(peer_id, summary);
+        Ok(())
+    }
+
+    fn remove_stale_entries(&self, max_peer_age_secs: u64, now_secs: u64) -> Result<(), Error> {
+        self.peer_summaries
+            .write()
+            .retain(|_, summary| now_secs.saturating_sub(summary.last_updated_secs) <= max_peer_age_secs);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_summary(last_updated_secs: u64) -> PersistedPeerSummary {
+        PersistedPeerSummary {
+            average_latency_secs: None,
+            ewma_latency_secs: None,
+            latest_node_info_response: None,
+            consecutive_failures: 0,
+            distance_from_validators: None,
+            last_updated_secs,
+        }
+    }
+
+    #[test]
+    fn save_and_load_round_trips_a_summary() {
+        let store = InMemoryPeerMonitoringStore::new(10);
+        let peer_id = PeerId::random();
+
+        store.save(peer_id, test_summary(100)).unwrap();
+
+        let loaded_summaries = store.load_all().unwrap();
+        assert_eq!(loaded_summaries.get(&peer_id), Some(&test_summary(100)));
+    }
+
+    #[test]
+    fn save_evicts_the_oldest_entry_once_the_store_is_full() {
+        let store = InMemoryPeerMonitoringStore::new(2);
+        let peer_one = PeerId::random();
+        let peer_two = PeerId::random();
+        let peer_three = PeerId::random();
+
+        store.save(peer_one, test_summary(1)).unwrap();
+        store.save(peer_two, test_summary(2)).unwrap();
+        store.save(peer_three, test_summary(3)).unwrap();
+
+        let loaded_summaries = store.load_all().unwrap();
+        assert_eq!(loaded_summaries.len(), 2);
+        assert!(!loaded_summaries.contains_key(&peer_one)); // The oldest entry was evicted
+        assert!(loaded_summaries.contains_key(&peer_two));
+        assert!(loaded_summaries.contains_key(&peer_three));
+    }
+
+    #[test]
+    fn save_overwriting_an_existing_peer_does_not_evict() {
+        let store = InMemoryPeerMonitoringStore::new(1);
+        let peer_id = PeerId::random();
+
+        store.save(peer_id, test_summary(1)).unwrap();
+        store.save(peer_id, test_summary(2)).unwrap();
+
+        let loaded_summaries = store.load_all().unwrap();
+        assert_eq!(loaded_summaries.len(), 1);
+        assert_eq!(loaded_summaries.get(&peer_id), Some(&test_summary(2)));
+    }
+
+    #[test]
+    fn remove_stale_entries_evicts_only_entries_past_the_max_age() {
+        let store = InMemoryPeerMonitoringStore::new(10);
+        let stale_peer = PeerId::random();
+        let fresh_peer = PeerId::random();
+
+        store.save(stale_peer, test_summary(0)).unwrap();
+        store.save(fresh_peer, test_summary(90)).unwrap();
+
+        store.remove_stale_entries(50, 100).unwrap();
+
+        let loaded_summaries = store.load_all().unwrap();
+        assert!(!loaded_summaries.contains_key(&stale_peer));
+        assert!(loaded_summaries.contains_key(&fresh_peer));
+    }
+}
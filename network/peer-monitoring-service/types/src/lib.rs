@@ -0,0 +1,43 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod request;
+pub mod response;
+
+use crate::{request::PeerMonitoringServiceRequest, response::PeerMonitoringServiceResponse};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub type Result<T, E = Error> = ::std::result::Result<T, E>;
+
+/// An error type for peer monitoring service operations
+#[derive(Clone, Debug, Deserialize, Eq, Error, PartialEq, Serialize)]
+pub enum Error {
+    #[error("Network error: {0}")]
+    NetworkError(String),
+
+    #[error("Unexpected error: {0}")]
+    UnexpectedError(String),
+}
+
+/// A single message type for peer monitoring service requests and responses
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum PeerMonitoringServiceMessage {
+    Request(PeerMonitoringServiceRequest),
+    Response(Result<PeerMonitoringServiceResponse>),
+}
+
+/// A summary of the peer monitoring metadata gathered for a given peer.
+/// This is updated by the monitoring client as new responses arrive, and
+/// consumed by other network clients that wish to make peer selection
+/// decisions (e.g., state sync and mempool).
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PeerMonitoringMetadata {
+    pub average_ping_latency_secs: Option<f64>, // The average latency ping for the peer
+    pub latest_network_info_response: Option<response::NetworkInformationResponse>, // The latest network info response
+    pub latest_node_info_response: Option<response::NodeInformationResponse>, // The latest node info response
+    pub connection_health: Option<response::ConnectionHealth>, // The peer's rolling connection-health summary
+    pub latency_percentiles: Option<response::LatencyPercentiles>, // The peer's sliding-window ping latency percentiles
+    pub ewma_ping_latency_secs: Option<f64>, // The exponentially weighted moving average of the peer's ping latency
+    pub known_peers: Vec<response::KnownPeerInfo>, // The candidate pool of peers gossiped by this peer, for discovery
+}
@@ -0,0 +1,52 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use cfg_block::cfg_block;
+use serde::{Deserialize, Serialize};
+
+/// A peer monitoring service request
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum PeerMonitoringServiceRequest {
+    GetKnownPeers, // Fetches a sampled set of peers known to (but not necessarily connected to) the peer
+    GetLatencyPing(LatencyPingRequest), // Sends a simple latency ping request
+    GetNetworkInformation, // Fetches the network information of the peer
+    GetNodeInformation,    // Fetches the node information of the peer
+    GetServerProtocolVersion, // Fetches the current server protocol version
+
+    #[cfg(feature = "network-perf-test")] // Disabled by default
+    PerformanceMonitoringRequest(PerformanceMonitoringRequest), // A request for performance monitoring
+}
+
+impl PeerMonitoringServiceRequest {
+    /// Returns a summary label for the request
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            Self::GetKnownPeers => "known_peers",
+            Self::GetLatencyPing(_) => "latency_ping",
+            Self::GetNetworkInformation => "network_information",
+            Self::GetNodeInformation => "node_information",
+            Self::GetServerProtocolVersion => "server_protocol_version",
+
+            #[cfg(feature = "network-perf-test")] // Disabled by default
+            Self::PerformanceMonitoringRequest(_) => "performance_monitoring_request",
+        }
+    }
+}
+
+/// A request for a simple latency ping
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct LatencyPingRequest {
+    pub ping_counter: u64, // A monotonically increasing counter to verify latency ping responses
+}
+
+cfg_block! {
+    #[cfg(feature = "network-perf-test")] { // Disabled by default
+        /// A request for performance monitoring (i.e., a throughput/bandwidth probe)
+        #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+        pub struct PerformanceMonitoringRequest {
+            pub request_counter: u64, // A monotonically increasing counter to verify responses
+            pub request_payload: Vec<u8>, // A padding blob, sized to estimate upload goodput
+            pub requested_response_bytes: u64, // The size of the padding blob requested in the response
+        }
+    }
+}
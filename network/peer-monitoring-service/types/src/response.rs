@@ -3,6 +3,7 @@
 
 use aptos_config::{config::PeerRole, network_id::PeerNetworkId};
 use aptos_types::{network_address::NetworkAddress, PeerId};
+use bitflags::bitflags;
 use cfg_block::cfg_block;
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, time::Duration};
@@ -12,6 +13,7 @@ use thiserror::Error;
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum PeerMonitoringServiceResponse {
+    KnownPeers(KnownPeersResponse), // Holds a sampled set of known peers (for discovery/bootstrapping)
     LatencyPing(LatencyPingResponse), // A simple message to respond to latency checks (i.e., pings)
     NetworkInformation(NetworkInformationResponse), // Holds the response for network information
     NodeInformation(NodeInformationResponse), // Holds the response for node information
@@ -25,6 +27,7 @@ impl PeerMonitoringServiceResponse {
     /// Returns a summary label for the response
     pub fn get_label(&self) -> &'static str {
         match self {
+            Self::KnownPeers(_) => "known_peers",
             Self::LatencyPing(_) => "latency_ping",
             Self::NetworkInformation(_) => "network_information",
             Self::NodeInformation(_) => "node_information",
@@ -85,6 +88,73 @@ pub struct ServerProtocolVersionResponse {
     pub version: u64, // The version of the peer monitoring service run by the server
 }
 
+bitflags! {
+    /// A bitset of capabilities advertised by a peer, used to help other peers
+    /// decide which known peers are worth dialing during discovery
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct PeerCapabilities: u8 {
+        const SUPPORTS_PERF_TEST = 1 << 0; // The peer supports performance monitoring requests
+        const IS_VALIDATOR_ADJACENT = 1 << 1; // The peer is directly connected to a validator
+        const IS_PUBLIC_FULLNODE = 1 << 2; // The peer is a public fullnode
+        const STORAGE_FULL_HISTORY = 1 << 3; // The peer stores the full transaction history
+    }
+}
+
+/// Information about a single peer known to (but not necessarily connected to)
+/// the peer serving the response
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct KnownPeerInfo {
+    pub peer_id: PeerId,
+    pub capabilities: PeerCapabilities,
+    pub freshness_timestamp_usecs: u64, // The last time this peer was observed to be alive
+}
+
+/// A response for the known peers request
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct KnownPeersResponse {
+    pub known_peers: Vec<KnownPeerInfo>, // A sampled set of peers known to the responder
+}
+
+/// A set of sliding-window latency percentiles (in seconds), computed over a
+/// bounded window of the most recent ping latencies observed for a peer
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LatencyPercentiles {
+    pub p50_secs: f64,
+    pub p90_secs: f64,
+    pub p99_secs: f64,
+}
+
+/// A coarse status label describing a peer's connection health, derived from
+/// the rolling success/failure history of monitoring requests sent to the peer
+/// (independent of which request type was sent).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ConnectionHealthStatus {
+    Healthy,
+    Degraded,
+    Unreachable,
+}
+
+impl ConnectionHealthStatus {
+    /// Returns a summary label for the status (e.g., for use as a metrics dimension)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Degraded => "degraded",
+            Self::Unreachable => "unreachable",
+        }
+    }
+}
+
+/// A summary of a peer's connection health, as observed by the monitoring client
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ConnectionHealth {
+    pub status: ConnectionHealthStatus, // The coarse connection health status
+    pub consecutive_failures: u64, // The number of consecutive monitoring request failures
+    pub num_successful_requests: u64, // The total number of successful monitoring requests
+    pub num_failed_requests: u64, // The total number of failed monitoring requests
+    pub secs_since_last_success: Option<u64>, // The time since the last successful request
+}
+
 /// A response for the node information request
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct NodeInformationResponse {
@@ -100,6 +170,20 @@ pub struct NodeInformationResponse {
 #[error("Unexpected response variant: {0}")]
 pub struct UnexpectedResponseError(pub String);
 
+impl TryFrom<PeerMonitoringServiceResponse> for KnownPeersResponse {
+    type Error = UnexpectedResponseError;
+
+    fn try_from(response: PeerMonitoringServiceResponse) -> crate::Result<Self, Self::Error> {
+        match response {
+            PeerMonitoringServiceResponse::KnownPeers(inner) => Ok(inner),
+            _ => Err(UnexpectedResponseError(format!(
+                "expected known_peers_response, found {}",
+                response.get_label()
+            ))),
+        }
+    }
+}
+
 impl TryFrom<PeerMonitoringServiceResponse> for LatencyPingResponse {
     type Error = UnexpectedResponseError;
 
@@ -162,6 +246,7 @@ cfg_block! {
         #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
         pub struct PerformanceMonitoringResponse {
             pub response_counter: u64, // A monotonically increasing counter to verify responses
+            pub response_payload: Vec<u8>, // A padding blob, sized to the request's requested_response_bytes
         }
 
         impl TryFrom<PeerMonitoringServiceResponse> for PerformanceMonitoringResponse {